@@ -0,0 +1,313 @@
+use crate::{
+	intersect::{Hit, Intersect},
+	ray::Ray,
+	triangle::Triangle,
+	vec3::Vec3,
+};
+
+/// Leaves stop splitting once they hold this few triangles or fewer.
+const MAX_LEAF_TRIANGLES: usize = 4;
+
+/// Most candidate split planes considered per internal node when
+/// estimating surface-area-heuristic cost.
+const SAH_BUCKETS: usize = 12;
+
+fn axis_component(v: Vec3, axis: usize) -> f64 {
+	match axis {
+		0 => v.0,
+		1 => v.1,
+		_ => v.2,
+	}
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Aabb {
+	min: Vec3,
+	max: Vec3,
+}
+
+impl Aabb {
+	fn degenerate() -> Self {
+		Self {
+			min: Vec3(f64::INFINITY, f64::INFINITY, f64::INFINITY),
+			max: Vec3(f64::NEG_INFINITY, f64::NEG_INFINITY, f64::NEG_INFINITY),
+		}
+	}
+
+	fn of_triangle(tri: &Triangle<Vec3>) -> Self {
+		let xs = [(tri.0).0, (tri.1).0, (tri.2).0];
+		let ys = [(tri.0).1, (tri.1).1, (tri.2).1];
+		let zs = [(tri.0).2, (tri.1).2, (tri.2).2];
+
+		Self {
+			min: Vec3(
+				xs.iter().cloned().fold(f64::INFINITY, f64::min),
+				ys.iter().cloned().fold(f64::INFINITY, f64::min),
+				zs.iter().cloned().fold(f64::INFINITY, f64::min),
+			),
+			max: Vec3(
+				xs.iter().cloned().fold(f64::NEG_INFINITY, f64::max),
+				ys.iter().cloned().fold(f64::NEG_INFINITY, f64::max),
+				zs.iter().cloned().fold(f64::NEG_INFINITY, f64::max),
+			),
+		}
+	}
+
+	fn union(&self, other: &Self) -> Self {
+		Self {
+			min: Vec3(
+				self.min.0.min(other.min.0),
+				self.min.1.min(other.min.1),
+				self.min.2.min(other.min.2),
+			),
+			max: Vec3(
+				self.max.0.max(other.max.0),
+				self.max.1.max(other.max.1),
+				self.max.2.max(other.max.2),
+			),
+		}
+	}
+
+	fn extent(&self) -> Vec3 {
+		self.max - self.min
+	}
+
+	fn centroid(&self) -> Vec3 {
+		(self.min + self.max) / 2_f64
+	}
+
+	fn surface_area(&self) -> f64 {
+		let e = self.extent();
+		if e.0 < 0.0 || e.1 < 0.0 || e.2 < 0.0 {
+			return 0.0;
+		}
+		2.0 * (e.0 * e.1 + e.1 * e.2 + e.2 * e.0)
+	}
+
+	/// The axis (0 = x, 1 = y, 2 = z) along which this box is widest.
+	fn largest_axis(&self) -> usize {
+		let e = self.extent();
+		if e.0 > e.1 && e.0 > e.2 {
+			0
+		} else if e.1 > e.2 {
+			1
+		} else {
+			2
+		}
+	}
+
+	/// Ray-AABB slab test. Finds the entry/exit `t` on every axis and rejects
+	/// if the intersection of those ranges is empty or lies entirely behind
+	/// the ray's origin.
+	fn hit(&self, ray: &Ray) -> bool {
+		let mut t_enter = f64::NEG_INFINITY;
+		let mut t_exit = f64::INFINITY;
+
+		for axis in 0..3 {
+			let origin = axis_component(ray.origin, axis);
+			let direction = axis_component(ray.direction, axis);
+			let min = axis_component(self.min, axis);
+			let max = axis_component(self.max, axis);
+
+			if direction.abs() < f64::EPSILON {
+				if origin < min || origin > max {
+					return false;
+				}
+				continue;
+			}
+
+			let mut t0 = (min - origin) / direction;
+			let mut t1 = (max - origin) / direction;
+			if t0 > t1 {
+				core::mem::swap(&mut t0, &mut t1);
+			}
+
+			t_enter = t_enter.max(t0);
+			t_exit = t_exit.min(t1);
+		}
+
+		t_enter <= t_exit && t_exit >= 0.0
+	}
+}
+
+enum BvhNode {
+	Leaf {
+		bounds: Aabb,
+		start: usize,
+		end: usize,
+	},
+	Internal {
+		bounds: Aabb,
+		left: usize,
+		right: usize,
+	},
+}
+
+impl BvhNode {
+	fn bounds(&self) -> Aabb {
+		match self {
+			Self::Leaf { bounds, .. } => *bounds,
+			Self::Internal { bounds, .. } => *bounds,
+		}
+	}
+}
+
+/// A bounding-volume hierarchy over a fixed set of triangles, built once and
+/// queried per ray in place of a flat triangle scan.
+pub struct Bvh {
+	nodes: Vec<BvhNode>,
+	triangles: Vec<Triangle<Vec3>>,
+}
+
+impl Bvh {
+	pub fn build(triangles: Vec<Triangle<Vec3>>) -> Self {
+		let bounds: Vec<Aabb> = triangles.iter().map(Aabb::of_triangle).collect();
+		let centroids: Vec<Vec3> = bounds.iter().map(Aabb::centroid).collect();
+		let mut indices: Vec<usize> = (0..triangles.len()).collect();
+
+		let mut nodes: Vec<BvhNode> = Vec::new();
+
+		if !triangles.is_empty() {
+			Self::build_range(&mut indices, &bounds, &centroids, 0, triangles.len(), &mut nodes);
+		}
+
+		let ordered: Vec<Triangle<Vec3>> = indices.iter().map(|&i| triangles[i]).collect();
+
+		Self {
+			nodes,
+			triangles: ordered,
+		}
+	}
+
+	fn root(&self) -> Option<usize> {
+		if self.nodes.is_empty() {
+			None
+		} else {
+			Some(self.nodes.len() - 1)
+		}
+	}
+
+	/// Recursively partitions `indices[start..end]`, pushing child nodes
+	/// before their parent so a node's index is always greater than both of
+	/// its children's. Returns the index of the node built for this range.
+	fn build_range(
+		indices: &mut [usize],
+		bounds: &[Aabb],
+		centroids: &[Vec3],
+		start: usize,
+		end: usize,
+		nodes: &mut Vec<BvhNode>,
+	) -> usize {
+		let node_bounds = indices[start..end]
+			.iter()
+			.fold(Aabb::degenerate(), |acc, &i| acc.union(&bounds[i]));
+
+		if end - start <= MAX_LEAF_TRIANGLES {
+			nodes.push(BvhNode::Leaf {
+				bounds: node_bounds,
+				start,
+				end,
+			});
+			return nodes.len() - 1;
+		}
+
+		let axis = node_bounds.largest_axis();
+
+		indices[start..end].sort_by(|&a, &b| {
+			axis_component(centroids[a], axis)
+				.partial_cmp(&axis_component(centroids[b], axis))
+				.unwrap_or(core::cmp::Ordering::Equal)
+		});
+
+		let mid = Self::best_split(indices, bounds, start, end).unwrap_or((start + end) / 2);
+
+		let left = Self::build_range(indices, bounds, centroids, start, mid, nodes);
+		let right = Self::build_range(indices, bounds, centroids, mid, end, nodes);
+
+		nodes.push(BvhNode::Internal {
+			bounds: node_bounds,
+			left,
+			right,
+		});
+		nodes.len() - 1
+	}
+
+	/// Surface-area-heuristic split search: estimate cost ≈ areaL·countL +
+	/// areaR·countR at a handful of candidate split points (the indices are
+	/// already sorted along the chosen axis) and return the cheapest one.
+	fn best_split(indices: &[usize], bounds: &[Aabb], start: usize, end: usize) -> Option<usize> {
+		let count = end - start;
+		if count < 2 {
+			return None;
+		}
+
+		let candidates = SAH_BUCKETS.min(count - 1).max(1);
+		let step = ((count - 1 + candidates - 1) / candidates).max(1);
+
+		let mut best: Option<(usize, f64)> = None;
+		let mut split = start + step;
+
+		while split < end {
+			let left_area = indices[start..split]
+				.iter()
+				.fold(Aabb::degenerate(), |acc, &i| acc.union(&bounds[i]))
+				.surface_area();
+			let right_area = indices[split..end]
+				.iter()
+				.fold(Aabb::degenerate(), |acc, &i| acc.union(&bounds[i]))
+				.surface_area();
+
+			let cost = left_area * (split - start) as f64 + right_area * (end - split) as f64;
+
+			if best.is_none_or(|(_, best_cost)| cost < best_cost) {
+				best = Some((split, cost));
+			}
+
+			split += step;
+		}
+
+		best.map(|(split, _)| split)
+	}
+}
+
+impl Intersect<&Bvh> for Ray {
+	type Record = Hit;
+
+	/// Descends only into boxes the ray hits, returning the nearest `Hit`
+	/// among the triangles in the leaves it reaches.
+	fn intersect(&self, bvh: &Bvh) -> Option<Hit> {
+		let root = bvh.root()?;
+
+		let mut stack = vec![root];
+		let mut closest: Option<Hit> = None;
+
+		while let Some(index) = stack.pop() {
+			let node = &bvh.nodes[index];
+
+			if !node.bounds().hit(self) {
+				continue;
+			}
+
+			match node {
+				BvhNode::Leaf { start, end, .. } => {
+					for tri in &bvh.triangles[*start..*end] {
+						if let Some(hit) = self.intersect(tri) {
+							if hit.time > self.t_offset && closest.is_none_or(|c| hit.time < c.time) {
+								closest = Some(hit);
+							}
+						}
+					}
+				}
+				BvhNode::Internal { left, right, .. } => {
+					stack.push(*left);
+					stack.push(*right);
+				}
+			}
+		}
+
+		closest
+	}
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,83 @@
+use crate::{bvh::Bvh, intersect::Intersect, ray::Ray, triangle::Triangle, vec3::Vec3};
+
+/// Four axis-aligned quads (as triangle pairs) spread along x, each offset in
+/// y/z so only one quad's slab test passes for any given probe ray below.
+fn scattered_triangles() -> Vec<Triangle<Vec3>> {
+	let quad = |x: f64, y: f64, z: f64| -> Vec<Triangle<Vec3>> {
+		vec![
+			Triangle(
+				Vec3(x, y - 1.0, z - 1.0),
+				Vec3(x, y + 1.0, z - 1.0),
+				Vec3(x, y + 1.0, z + 1.0),
+			),
+			Triangle(
+				Vec3(x, y - 1.0, z - 1.0),
+				Vec3(x, y + 1.0, z + 1.0),
+				Vec3(x, y - 1.0, z + 1.0),
+			),
+		]
+	};
+
+	vec![quad(2.0, 0.0, 0.0), quad(5.0, 4.0, 0.0), quad(9.0, -4.0, 2.0), quad(12.0, 0.0, -3.0)]
+		.into_iter()
+		.flatten()
+		.collect()
+}
+
+fn nearest_via_flat_scan(ray: &Ray, triangles: &[Triangle<Vec3>]) -> Option<f64> {
+	triangles
+		.iter()
+		.filter_map(|tri| ray.intersect(tri))
+		.filter(|hit| hit.time > ray.t_offset)
+		.map(|hit| hit.time)
+		.fold(None, |closest, time| match closest {
+			Some(closest) if closest <= time => Some(closest),
+			_ => Some(time),
+		})
+}
+
+#[test]
+fn bvh_traversal_matches_a_flat_scan_over_several_rays() {
+	let triangles = scattered_triangles();
+	let bvh = Bvh::build(triangles.clone());
+
+	let rays = [
+		Ray::new(Vec3(-1.0, 0.0, 0.0), Vec3(1.0, 0.0, 0.0)),
+		Ray::new(Vec3(-1.0, 4.0, 0.0), Vec3(1.0, 0.0, 0.0)),
+		Ray::new(Vec3(-1.0, -4.0, 2.0), Vec3(1.0, 0.0, 0.0)),
+		Ray::new(Vec3(-1.0, 0.0, -3.0), Vec3(1.0, 0.0, 0.0)),
+		Ray::new(Vec3(-1.0, 100.0, 100.0), Vec3(1.0, 0.0, 0.0)),
+	];
+
+	for ray in rays {
+		let expected = nearest_via_flat_scan(&ray, &triangles);
+		let actual = ray.intersect(&bvh).map(|hit| hit.time);
+
+		assert_eq!(actual, expected, "bvh and flat scan disagreed for {ray:?}");
+	}
+}
+
+#[test]
+fn bvh_traversal_misses_an_axis_parallel_ray_outside_every_box() {
+	let triangles = scattered_triangles();
+	let bvh = Bvh::build(triangles);
+
+	// Travels along y, never changing x, starting outside every quad's x
+	// range: each box's x-slab test must reject it without dividing by a
+	// near-zero direction component.
+	let ray = Ray::new(Vec3(100.0, 0.0, 0.0), Vec3(0.0, 1.0, 0.0));
+
+	assert_eq!(ray.intersect(&bvh), None);
+}
+
+#[test]
+fn bvh_traversal_ignores_geometry_behind_the_ray_origin() {
+	let triangles = scattered_triangles();
+	let bvh = Bvh::build(triangles);
+
+	// All geometry sits at positive x; pointing back along -x should never
+	// report a hit even though the boxes lie on the ray's infinite line.
+	let ray = Ray::new(Vec3(0.0, 0.0, 0.0), Vec3(-1.0, 0.0, 0.0));
+
+	assert_eq!(ray.intersect(&bvh), None);
+}
@@ -1,4 +1,7 @@
+mod bvh;
 mod intersect;
+mod mat4;
+mod ops;
 mod products;
 mod ray;
 mod scene;
@@ -7,9 +10,10 @@ mod triangle;
 mod vec3;
 
 pub use intersect::{Hit, Intersect};
+pub use mat4::{Mat4, Transform};
 pub use products::{CrossProduct, DotProduct};
 pub use ray::Ray;
-pub use scene::{build_geometry_from_triangle_fan, Emitter, Object, Receiver, Scene, Sound};
+pub use scene::{build_geometry_from_triangle_fan, Emitter, Object, Sampling, Scene, Sound};
 pub use sphere::Sphere;
 pub use triangle::Triangle;
 pub use vec3::Vec3;
@@ -1,10 +1,6 @@
-use acray::{
-	build_geometry_from_triangle_fan, Emitter, Hit, Object, Receiver, Scene, Sphere, Vec3,
-};
+use acray::{build_geometry_from_triangle_fan, Emitter, Hit, Object, Sampling, Scene, Sphere, Vec3};
 
 use std::fs::File;
-use std::io;
-use std::io::prelude::*;
 
 fn main() {
 	#[cfg(feature = "simple_logger")]
@@ -13,73 +9,83 @@ fn main() {
 	let emitter: Emitter = Emitter {
 		origin: Vec3(1.0, 0.0, 0.0),
 		sounds_per_tick: 100000,
+		directions: Sampling::Stratified {
+			bands: 200,
+			sectors: 500,
+		},
 	};
 
-	let receiver: Receiver = Receiver::Spherical(Sphere::new(Vec3(0_f32, 0_f32, 0_f32), 0.1_f32));
+	let receiver: Object = Object::receiver(Sphere::new(Vec3(0_f64, 0_f64, 0_f64), 0.1_f64));
 
-	let front_wall: Object = Object::new(
+	let front_wall: Object = Object::reflector(
 		build_geometry_from_triangle_fan(vec![
-			Vec3(10.0_f32, -5_f32, -5_f32),
-			Vec3(10.0_f32, -5_f32, 5_f32),
-			Vec3(10.0_f32, 5_f32, 5_f32),
-			Vec3(10.0_f32, 5_f32, -5_f32),
+			Vec3(10.0_f64, -5_f64, -5_f64),
+			Vec3(10.0_f64, -5_f64, 5_f64),
+			Vec3(10.0_f64, 5_f64, 5_f64),
+			Vec3(10.0_f64, 5_f64, -5_f64),
 		]),
-		0.8_f32,
+		0.8_f64,
+		0.5_f64,
 	);
 
-	let back_wall: Object = Object::new(
+	let back_wall: Object = Object::reflector(
 		build_geometry_from_triangle_fan(vec![
-			Vec3(-10.0_f32, -5_f32, -5_f32),
-			Vec3(-10.0_f32, -5_f32, 5_f32),
-			Vec3(-10.0_f32, 5_f32, 5_f32),
-			Vec3(-10.0_f32, 5_f32, -5_f32),
+			Vec3(-10.0_f64, -5_f64, -5_f64),
+			Vec3(-10.0_f64, -5_f64, 5_f64),
+			Vec3(-10.0_f64, 5_f64, 5_f64),
+			Vec3(-10.0_f64, 5_f64, -5_f64),
 		]),
-		0.8_f32,
+		0.8_f64,
+		0.5_f64,
 	);
 
-	let top_wall: Object = Object::new(
+	let top_wall: Object = Object::reflector(
 		build_geometry_from_triangle_fan(vec![
-			Vec3(-10.0_f32, -5_f32, 5_f32),
-			Vec3(10.0_f32, -5_f32, 5_f32),
-			Vec3(10.0_f32, 5_f32, 5_f32),
-			Vec3(-10.0_f32, 5_f32, 5_f32),
+			Vec3(-10.0_f64, -5_f64, 5_f64),
+			Vec3(10.0_f64, -5_f64, 5_f64),
+			Vec3(10.0_f64, 5_f64, 5_f64),
+			Vec3(-10.0_f64, 5_f64, 5_f64),
 		]),
-		0.8_f32,
+		0.8_f64,
+		0.5_f64,
 	);
 
-	let bottom_wall: Object = Object::new(
+	let bottom_wall: Object = Object::reflector(
 		build_geometry_from_triangle_fan(vec![
-			Vec3(-10.0_f32, -5_f32, -5_f32),
-			Vec3(10.0_f32, -5_f32, -5_f32),
-			Vec3(10.0_f32, 5_f32, -5_f32),
-			Vec3(-10.0_f32, 5_f32, -5_f32),
+			Vec3(-10.0_f64, -5_f64, -5_f64),
+			Vec3(10.0_f64, -5_f64, -5_f64),
+			Vec3(10.0_f64, 5_f64, -5_f64),
+			Vec3(-10.0_f64, 5_f64, -5_f64),
 		]),
-		0.8_f32,
+		0.8_f64,
+		0.5_f64,
 	);
 
-	let left_wall: Object = Object::new(
+	let left_wall: Object = Object::reflector(
 		build_geometry_from_triangle_fan(vec![
-			Vec3(-10.0_f32, -5_f32, 5_f32),
-			Vec3(10.0_f32, -5_f32, 5_f32),
-			Vec3(10.0_f32, -5_f32, -5_f32),
-			Vec3(-10.0_f32, -5_f32, -5_f32),
+			Vec3(-10.0_f64, -5_f64, 5_f64),
+			Vec3(10.0_f64, -5_f64, 5_f64),
+			Vec3(10.0_f64, -5_f64, -5_f64),
+			Vec3(-10.0_f64, -5_f64, -5_f64),
 		]),
-		0.8_f32,
+		0.8_f64,
+		0.5_f64,
 	);
 
-	let right_wall: Object = Object::new(
+	let right_wall: Object = Object::reflector(
 		build_geometry_from_triangle_fan(vec![
-			Vec3(-10.0_f32, 5_f32, 5_f32),
-			Vec3(10.0_f32, 5_f32, 5_f32),
-			Vec3(10.0_f32, 5_f32, -5_f32),
-			Vec3(-10.0_f32, 5_f32, -5_f32),
+			Vec3(-10.0_f64, 5_f64, 5_f64),
+			Vec3(10.0_f64, 5_f64, 5_f64),
+			Vec3(10.0_f64, 5_f64, -5_f64),
+			Vec3(-10.0_f64, 5_f64, -5_f64),
 		]),
-		0.8_f32,
+		0.8_f64,
+		0.5_f64,
 	);
 
 	let mut scene: Scene = Scene::new()
 		.emitter(emitter)
-		.receiver(receiver)
+		.object(receiver)
 		.object(front_wall)
 		.object(back_wall)
 		.object(top_wall)
@@ -89,13 +95,13 @@ fn main() {
 
 	println!("Starting simulation...");
 
-	let results: Vec<(Hit, f32)> = scene.simulate();
-	let mut file: File = File::create("results.csv").expect("Failed to open results.csv");
+	let results: Vec<(Hit, f64)> = scene.simulate();
+	let file: File = File::create("results.csv").expect("Failed to open results.csv");
 
 	let mut writer = csv::Writer::from_writer(file);
 
 	writer
-		.write_record(&["time", "amplitude"])
+		.write_record(["time", "amplitude"])
 		.expect("Failed to write headers");
 
 	for (hit, amplitude) in results {
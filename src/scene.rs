@@ -4,14 +4,18 @@ use std::collections::BTreeSet;
 use log::{debug, error, info, trace, warn};
 
 use crate::{
+	bvh::Bvh,
 	intersect::{Hit, Intersect},
-	products::DotProduct,
+	mat4::{Mat4, Transform},
+	ops::f64 as ops,
+	products::{CrossProduct, DotProduct},
 	ray::Ray,
 	sphere::Sphere,
 	triangle::Triangle,
 	vec3::Vec3,
 };
 
+#[derive(Default)]
 pub struct Scene {
 	objects: Vec<Object>,
 	emitters: Vec<Emitter>,
@@ -22,55 +26,93 @@ pub struct Scene {
 pub struct Emitter {
 	pub origin: Vec3,
 	pub sounds_per_tick: usize,
+	pub directions: Sampling,
+}
+
+/// How an `Emitter` distributes its rays' directions over the unit sphere.
+#[derive(Debug, PartialEq)]
+pub enum Sampling {
+	/// `theta` and `phi` are drawn independently per ray, which clumps
+	/// directions and leaves gaps at low ray counts.
+	Uniform,
+	/// The sphere is partitioned into an equal-solid-angle grid of `bands`
+	/// (in `cos(phi)`) by `sectors` (in `theta`) cells, and rays are handed
+	/// out one per cell (wrapping once all cells are used) with a jittered
+	/// offset inside it. This covers the sphere far more evenly than
+	/// `Uniform` at the same ray count.
+	Stratified { bands: usize, sectors: usize },
 }
 
 pub enum Object {
 	Reflector {
-		geometry: Vec<Triangle<Vec3>>,
+		geometry: Bvh,
 		reflectance: f64,
+		/// Fraction of hits that scatter diffusely (cosine-weighted about the
+		/// hit normal) instead of reflecting specularly. `0.0` is a perfect
+		/// mirror; `1.0` is a fully rough/diffuse surface.
+		scattering: f64,
+		transform: Option<Transform>,
 	},
 	Receiver {
 		geometry: Sphere,
+		transform: Option<Transform>,
 	},
 }
 
 impl Object {
-	pub fn reflector(geometry: Vec<Triangle<Vec3>>, reflectance: f64) -> Self {
+	pub fn reflector(geometry: Vec<Triangle<Vec3>>, reflectance: f64, scattering: f64) -> Self {
 		Self::Reflector {
-			geometry,
+			geometry: Bvh::build(geometry),
+			reflectance,
+			scattering,
+			transform: None,
+		}
+	}
+
+	/// Like [`Object::reflector`], but instances the geometry under `transform`
+	/// instead of requiring it to already be laid out in world coordinates.
+	pub fn reflector_with_transform(
+		geometry: Vec<Triangle<Vec3>>,
+		reflectance: f64,
+		scattering: f64,
+		transform: Mat4,
+	) -> Self {
+		Self::Reflector {
+			geometry: Bvh::build(geometry),
 			reflectance,
+			scattering,
+			transform: Some(Transform::new(transform)),
 		}
 	}
 
 	pub fn receiver(geometry: Sphere) -> Self {
-		Self::Receiver { geometry }
+		Self::Receiver {
+			geometry,
+			transform: None,
+		}
+	}
+
+	/// Like [`Object::receiver`], but repositions/resizes `geometry` under
+	/// `transform` instead of requiring it to already be in world space.
+	pub fn receiver_with_transform(geometry: Sphere, transform: Mat4) -> Self {
+		Self::Receiver {
+			geometry,
+			transform: Some(Transform::new(transform)),
+		}
 	}
 }
 
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub enum Interaction {
 	ReceiverHit { hit: Hit, intensity: f64 },
-	ObjectHit { hit: Hit, reflectance: f64 },
+	ObjectHit { hit: Hit, reflectance: f64, scattering: f64 },
 }
 
 use core::cmp::Ordering;
 
 impl core::cmp::PartialOrd for Interaction {
 	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-		match (self, other) {
-			(Self::ObjectHit { hit: hit_a, .. }, Self::ObjectHit { hit: hit_b, .. }) => {
-				Some(hit_a.cmp(hit_b))
-			}
-			(Self::ReceiverHit { hit: hit_a, .. }, Self::ReceiverHit { hit: hit_b, .. }) => {
-				Some(hit_a.cmp(hit_b))
-			}
-			(Self::ObjectHit { hit: hit_a, .. }, Self::ReceiverHit { hit: hit_b, .. }) => {
-				Some(hit_a.cmp(hit_b))
-			}
-			(Self::ReceiverHit { hit: hit_a, .. }, Self::ObjectHit { hit: hit_b, .. }) => {
-				Some(hit_a.cmp(hit_b))
-			}
-		}
+		Some(self.cmp(other))
 	}
 }
 
@@ -148,11 +190,48 @@ fn triangle_fan_four_points_two_triangles() {
 	)
 }
 
+/// Draws a cosine-weighted direction on the hemisphere around `normal`,
+/// used to model diffuse acoustic scattering off a rough `Reflector`.
+fn cosine_weighted_scatter<R: rand::Rng>(normal: Vec3, rng: &mut R) -> Vec3 {
+	use core::f64::consts::PI;
+
+	let u1: f64 = rng.gen_range(0_f64, 1_f64);
+	let u2: f64 = rng.gen_range(0_f64, 1_f64);
+
+	let r: f64 = ops::sqrt(u1);
+	let theta: f64 = 2_f64 * PI * u2;
+
+	let local: Vec3 = Vec3(r * ops::cos(theta), r * ops::sin(theta), ops::sqrt(1_f64 - u1));
+
+	// Build an orthonormal basis around `normal` by picking any tangent not
+	// parallel to it, then rotate the local-space sample into world space.
+	let tangent: Vec3 = if normal.0.abs() > 0.9 {
+		Vec3(0_f64, 1_f64, 0_f64)
+	} else {
+		Vec3(1_f64, 0_f64, 0_f64)
+	};
+
+	let bitangent: Vec3 = normal.cross(tangent).unit();
+	let tangent: Vec3 = bitangent.cross(normal).unit();
+
+	(tangent * local.0 + bitangent * local.1 + normal * local.2).unit()
+}
+
+#[derive(Clone)]
 pub struct Sound {
 	ray: Ray,
 	intensity: f64,
 }
 
+/// The outcome of propagating a single `Sound` for one tick: a continuation
+/// ray to carry into the next tick, a receiver hit, or neither. Keeping this
+/// as a plain, `Send` value lets each ray be resolved independently in the
+/// parallel fold below, with no shared mutable state to synchronize.
+struct TickResult {
+	next: Option<Sound>,
+	received: Option<(Hit, f64)>,
+}
+
 impl Scene {
 	pub fn new() -> Self {
 		Self::default()
@@ -180,9 +259,21 @@ impl Scene {
 		&self.sounds
 	}
 
+	/// Runs the simulation with a fresh `rand::thread_rng()`. Results will
+	/// differ between runs; use [`Scene::simulate_with_rng`] with a seeded
+	/// RNG (e.g. `rand_pcg::Pcg64`) for a bit-reproducible impulse response.
 	pub fn simulate(&mut self) -> Vec<(Hit, f64)> {
-		use rand::Rng;
-		let mut rng = rand::thread_rng();
+		self.simulate_with_rng(&mut rand::thread_rng())
+	}
+
+	/// Runs the simulation, drawing all randomness from `rng`. Passing a
+	/// seeded RNG makes the resulting impulse response deterministic and
+	/// reproducible across machines (pair with the `libm` feature for
+	/// cross-platform bit-reproducibility of the underlying math too).
+	pub fn simulate_with_rng<R: rand::Rng>(&mut self, rng: &mut R) -> Vec<(Hit, f64)> {
+		use rand::{Rng, SeedableRng};
+		use rand_pcg::Pcg64;
+		use rayon::prelude::*;
 
 		const SPEED_OF_SOUND: f64 = 344_f64;
 
@@ -194,34 +285,61 @@ impl Scene {
 		let sounds: Vec<Sound> = self
 			.emitters()
 			.iter()
-			.map(|emitter| -> Vec<Sound> {
+			.flat_map(|emitter| -> Vec<Sound> {
+				use core::f64::consts::PI;
+
 				let sounds_to_emit: usize = emitter.sounds_per_tick;
 
 				debug!("Emitting {} sounds...", sounds_to_emit);
 
-				(0..sounds_to_emit)
-					.map(|_| {
-						use core::f64::consts::PI;
+				let emit = |theta: f64, phi: f64| -> Sound {
+					let direction: Vec3 = Vec3(
+						ops::sin(phi) * ops::cos(theta),
+						ops::sin(phi) * ops::sin(theta),
+						ops::cos(phi),
+					);
 
-						let theta: f64 = rng.gen_range(0_f64, 2_f64 * PI);
+					let direction: Vec3 = direction * (SPEED_OF_SOUND / direction.mag());
 
-						let phi: f64 = (2_f64 * rng.gen_range(0_f64, 1_f64) - 1_f64).acos();
+					Sound {
+						ray: Ray::new(emitter.origin, direction),
+						intensity: 1_f64,
+					}
+				};
 
-						let direction: Vec3 = Vec3(phi.sin() * theta.cos(), phi.sin() * theta.sin(), phi.cos());
+				match &emitter.directions {
+					Sampling::Uniform => (0..sounds_to_emit)
+						.map(|_| {
+							let theta: f64 = rng.gen_range(0_f64, 2_f64 * PI);
+							let phi: f64 = ops::acos(2_f64 * rng.gen_range(0_f64, 1_f64) - 1_f64);
 
-						let direction: Vec3 = direction * (SPEED_OF_SOUND / direction.mag());
+							emit(theta, phi)
+						})
+						.collect(),
 
-						Sound {
-							ray: Ray::new(emitter.origin, direction),
-							intensity: 1_f64,
-						}
-					})
-					.collect()
+					Sampling::Stratified { bands, sectors } => {
+						let total_cells: usize = bands * sectors;
+
+						(0..sounds_to_emit)
+							.map(|i| {
+								let cell: usize = i % total_cells;
+								let band: usize = cell / sectors;
+								let sector: usize = cell % sectors;
+
+								let v: f64 = (band as f64 + rng.gen_range(0_f64, 1_f64)) / *bands as f64;
+								let u: f64 = (sector as f64 + rng.gen_range(0_f64, 1_f64)) / *sectors as f64;
+
+								let theta: f64 = 2_f64 * PI * u;
+								let phi: f64 = ops::acos(2_f64 * v - 1_f64);
+
+								emit(theta, phi)
+							})
+							.collect()
+					}
+				}
 			})
-			.flatten()
 			.collect();
 
-		let mut time = 0_f64;
 		let mut sounds = sounds;
 
 		loop {
@@ -231,41 +349,68 @@ impl Scene {
 				break;
 			}
 
-			sounds = sounds
-				.iter()
-				.map(|sound: &Sound| -> Option<Sound> {
+			// Drawn sequentially so each ray's per-tick RNG below is still a
+			// pure function of (seed, ray index), keeping the whole run
+			// reproducible despite resolving rays in parallel.
+			let tick_seed: u64 = rng.gen();
+
+			let results: Vec<TickResult> = sounds
+				.into_par_iter()
+				.enumerate()
+				.map(|(index, sound): (usize, Sound)| -> TickResult {
 					let hits: BTreeSet<Interaction> = self
 						.objects
 						.iter()
-						.map(|object| -> BTreeSet<Interaction> {
+						.flat_map(|object| -> BTreeSet<Interaction> {
 							match object {
 								Object::Reflector {
 									geometry,
 									reflectance,
-								} => geometry
-									.iter()
-									.map(|tri| -> Option<Interaction> {
-										sound.ray.intersect(tri).map(|hit| Interaction::ObjectHit {
+									scattering,
+									transform,
+								} => {
+									let local_ray = match transform {
+										Some(t) => t.to_local(&sound.ray),
+										None => sound.ray,
+									};
+
+									local_ray
+										.intersect(geometry)
+										.map(|hit| match transform {
+											Some(t) => t.to_world(hit),
+											None => hit,
+										})
+										.map(|hit| Interaction::ObjectHit {
 											hit,
 											reflectance: *reflectance,
+											scattering: *scattering,
 										})
-									})
-									.filter_map(|x| x)
-									.collect(),
-
-								Object::Receiver { geometry } => sound
-									.ray
-									.intersect(geometry)
-									.unwrap_or(vec![])
-									.iter()
-									.map(|hit| Interaction::ReceiverHit {
-										hit: *hit,
-										intensity: sound.intensity,
-									})
-									.collect(),
+										.into_iter()
+										.collect()
+								}
+
+								Object::Receiver { geometry, transform } => {
+									let local_ray = match transform {
+										Some(t) => t.to_local(&sound.ray),
+										None => sound.ray,
+									};
+
+									local_ray
+										.intersect(geometry)
+										.unwrap_or(vec![])
+										.into_iter()
+										.map(|hit| match transform {
+											Some(t) => t.to_world(hit),
+											None => hit,
+										})
+										.map(|hit| Interaction::ReceiverHit {
+											hit,
+											intensity: sound.intensity,
+										})
+										.collect()
+								}
 							}
 						})
-						.flatten()
 						.filter(|hit| -> bool {
 							match hit {
 								Interaction::ObjectHit { hit, .. } => hit.time > sound.ray.t_offset,
@@ -276,62 +421,88 @@ impl Scene {
 
 					let earliest_hit = hits.iter().nth(0);
 
-					earliest_hit
-						.map(|interaction| -> Option<Sound> {
-							match interaction {
-								Interaction::ObjectHit { hit, reflectance } => {
-									let direction: Vec3 = sound.ray.direction
-										- 2_f64 * (sound.ray.direction.dot(hit.unit_normal)) * hit.unit_normal;
-									let origin: Vec3 = hit.point;
-									let t_offset: f64 = hit.time;
-
-									let new_ray: Ray = Ray {
-										direction,
-										origin,
-										t_offset,
-									};
-
-									time = hit.time;
-
-									let new_intensity: f64 = sound.intensity * reflectance;
-
-									// If the new intensity isn't super low (near the
-									// threshold of human hearing) we should probably
-									// just kill it off.
-									if new_intensity >= 0.000_000_001 {
-										Some(Sound {
-											ray: new_ray,
-											intensity: new_intensity,
-										})
-									} else {
-										trace!("Killing sound because its amplitude is too low!");
-										None
-									}
+					match earliest_hit {
+						Some(Interaction::ObjectHit {
+							hit,
+							reflectance,
+							scattering,
+						}) => {
+							// Each ray gets its own RNG, seeded from the tick's
+							// base seed and the ray's position in the batch, so
+							// the parallel fold above never has to share a
+							// single generator across threads.
+							let mut ray_rng = Pcg64::seed_from_u64(tick_seed ^ index as u64);
+
+							let direction: Vec3 = if ray_rng.gen_range(0_f64, 1_f64) < *scattering {
+								// `hit.unit_normal` is winding-defined, not
+								// oriented toward the incoming ray, so flip it
+								// onto the incoming side before sampling or the
+								// scatter can land in the far hemisphere and
+								// tunnel through the surface.
+								let incoming_normal = if sound.ray.direction.dot(hit.unit_normal) > 0.0 {
+									-hit.unit_normal
+								} else {
+									hit.unit_normal
+								};
+
+								cosine_weighted_scatter(incoming_normal, &mut ray_rng) * sound.ray.direction.mag()
+							} else {
+								sound.ray.direction
+									- 2_f64 * (sound.ray.direction.dot(hit.unit_normal)) * hit.unit_normal
+							};
+							let origin: Vec3 = hit.point;
+							let t_offset: f64 = hit.time;
+
+							let new_ray: Ray = Ray {
+								direction,
+								origin,
+								t_offset,
+							};
+
+							let new_intensity: f64 = sound.intensity * reflectance;
+
+							// If the new intensity is super low (near the
+							// threshold of human hearing) we should probably
+							// just kill it off.
+							if new_intensity >= 0.000_000_001 {
+								TickResult {
+									next: Some(Sound {
+										ray: new_ray,
+										intensity: new_intensity,
+									}),
+									received: None,
 								}
-								Interaction::ReceiverHit { hit, intensity } => {
-									receiver_hits.push((*hit, *intensity));
-									None
+							} else {
+								trace!("Killing sound because its amplitude is too low!");
+								TickResult {
+									next: None,
+									received: None,
 								}
 							}
-						})
-						.flatten()
+						}
+						Some(Interaction::ReceiverHit { hit, intensity }) => TickResult {
+							next: None,
+							received: Some((*hit, *intensity)),
+						},
+						None => TickResult {
+							next: None,
+							received: None,
+						},
+					}
 				})
-				.filter_map(|x| x)
 				.collect();
 
+			let (next_sounds, new_hits): (Vec<_>, Vec<_>) = results
+				.into_iter()
+				.map(|result| (result.next, result.received))
+				.unzip();
+
+			sounds = next_sounds.into_iter().flatten().collect();
+			receiver_hits.extend(new_hits.into_iter().flatten());
+
 			info!("EOT with {} sounds", sounds.len());
 		}
 
 		receiver_hits
 	}
 }
-
-impl Default for Scene {
-	fn default() -> Self {
-		Self {
-			objects: Vec::default(),
-			emitters: Vec::default(),
-			sounds: Vec::default(),
-		}
-	}
-}
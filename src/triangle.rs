@@ -1,4 +1,4 @@
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct Triangle<V>(pub V, pub V, pub V)
 where
 	V: Sized + Copy + Clone + core::fmt::Debug;